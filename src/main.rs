@@ -1,69 +1,376 @@
-use image::{png::PNGEncoder, ColorType};
+use image::{png::PNGEncoder, pnm::PNMEncoder, ColorType};
 use num::Complex;
-use std::{fmt::Debug, fs::File, io, str::FromStr};
+use rand::Rng;
+use rayon::prelude::*;
+use std::{fmt, fmt::Debug, fs::File, io, path::Path, str::FromStr};
+use tiff::encoder::{colortype, compression::Deflate, TiffEncoder};
 
 type Point = (usize, usize);
 
+/// Default number of random orbits sampled by `--buddhabrot`.
+const DEFAULT_BUDDHABROT_SAMPLES: u32 = 10_000_000;
+
+/// Default per-orbit iteration cap for `--buddhabrot`.
+const DEFAULT_BUDDHABROT_LIMIT: u32 = 500;
+
+/// Number of image rows handed to a single rayon task.
+///
+/// Smaller bands balance work more evenly across threads but add overhead;
+/// this value is a reasonable middle ground for typical image sizes.
+const ROWS_PER_BAND: usize = 8;
+
 struct Corner {
     upper_left: Complex<f64>,
     lower_right: Complex<f64>,
 }
 
+/// The fractal family to iterate when rendering.
+///
+/// `MandelbrotP(p)` generalizes the classic `z*z + c` recurrence to
+/// `z.powu(p) + c`, so `MandelbrotP(2)` is equivalent to `Mandelbrot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FractalKind {
+    Mandelbrot,
+    MandelbrotP(u32),
+    BurningShip,
+}
+
+impl FromStr for FractalKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mandelbrot" => Ok(FractalKind::Mandelbrot),
+            "burning_ship" => Ok(FractalKind::BurningShip),
+            other => other
+                .strip_prefix("mandelbrot")
+                .and_then(|power| power.parse::<u32>().ok())
+                .map(FractalKind::MandelbrotP)
+                .ok_or_else(|| format!("unknown fractal kind {:?}", other)),
+        }
+    }
+}
+
+/// How escape times are mapped to pixel bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorMode {
+    /// One byte per pixel, banded by raw escape count.
+    Grayscale,
+    /// Three bytes per pixel, continuously shaded by normalized escape count.
+    Smooth,
+}
+
+impl ColorMode {
+    fn bytes_per_pixel(self) -> usize {
+        match self {
+            ColorMode::Grayscale => 1,
+            ColorMode::Smooth => 3,
+        }
+    }
+}
+
+impl FromStr for ColorMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "grayscale" => Ok(ColorMode::Grayscale),
+            "smooth" => Ok(ColorMode::Smooth),
+            other => Err(format!("unknown color mode {:?}", other)),
+        }
+    }
+}
+
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+
+    let fractal_kind = extract_flag_value(&mut args, "--fractal")
+        .map(|value| {
+            value.parse().unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1)
+            })
+        })
+        .unwrap_or(FractalKind::Mandelbrot);
+
+    let color_mode = extract_flag_value(&mut args, "--color")
+        .map(|value| {
+            value.parse().unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1)
+            })
+        })
+        .unwrap_or(ColorMode::Grayscale);
+
+    let buddhabrot = extract_switch(&mut args, "--buddhabrot");
+
+    let buddhabrot_samples = extract_flag_value(&mut args, "--samples")
+        .map(|value| value.parse().expect("error parsing sample count"))
+        .unwrap_or(DEFAULT_BUDDHABROT_SAMPLES);
+
+    let buddhabrot_limit = extract_flag_value(&mut args, "--limit")
+        .map(|value| value.parse().expect("error parsing iteration limit"))
+        .unwrap_or(DEFAULT_BUDDHABROT_LIMIT);
 
     if args.len() != 5 {
         show_proper_usage(&args);
     }
 
+    if let Some(threads) = thread_limit_from_env() {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .expect("error building rayon thread pool");
+    }
+
     let bounds = parse_pair::<usize>(&args[2], 'x').expect("error parsing image dimensions");
     let upper_left = parse_complex(&args[3]).expect("error parsing upper left corner point");
     let lower_right = parse_complex(&args[4]).expect("error parsing lower right corner point");
+    let corner = Corner {
+        upper_left,
+        lower_right,
+    };
 
     let (width, height) = bounds;
-    let mut pixels = vec![0; width * height];
 
-    render(
-        &mut pixels,
-        bounds,
-        Corner {
-            upper_left,
-            lower_right,
-        },
-    );
+    if buddhabrot {
+        let mut histogram = vec![0_u32; width * height];
+        render_buddhabrot(
+            &mut histogram,
+            bounds,
+            &corner,
+            buddhabrot_samples,
+            buddhabrot_limit,
+        );
+
+        let pixels = normalize_histogram(&histogram);
+        write_image(&args[1], &pixels, bounds, ColorMode::Grayscale)
+            .expect("error writing image file");
+        return;
+    }
+
+    let mut pixels = vec![0; width * height * color_mode.bytes_per_pixel()];
+
+    render(&mut pixels, bounds, corner, fractal_kind, color_mode);
+
+    write_image(&args[1], &pixels, bounds, color_mode).expect("error writing image file")
+}
+
+/// Removes `flag` from `args` if present, returning whether it was there.
+fn extract_switch(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|arg| arg == flag) {
+        Some(index) => {
+            args.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Removes `flag` and the value following it from `args`, if present.
+///
+/// Exits with an error if `flag` is present but has no following value,
+/// rather than silently treating it as absent.
+fn extract_flag_value(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == flag)?;
+    args.remove(index);
 
-    write_image(&args[1], &pixels, bounds).expect("error writing png file")
+    if index < args.len() {
+        Some(args.remove(index))
+    } else {
+        eprintln!("Error: {} requires a value", flag);
+        std::process::exit(1)
+    }
+}
+
+/// Reads `MANDELBROT_THREADS` to let users cap how many rayon worker threads
+/// render the image. Absent or unparsable values leave rayon's default.
+fn thread_limit_from_env() -> Option<usize> {
+    std::env::var("MANDELBROT_THREADS")
+        .ok()
+        .and_then(|value| value.parse().ok())
 }
 
 fn show_proper_usage(args: &[String]) {
-    eprintln!("Usage: mandelbrot FILE PIXELS UPPERLEFT LOWERRIGHT");
     eprintln!(
-        "Example: {} mandel.png 1000x750 -1.20,0.35 -1,0.20",
+        "Usage: mandelbrot FILE PIXELS UPPERLEFT LOWERRIGHT [--fractal KIND] [--color MODE] \
+         [--buddhabrot [--samples N] [--limit N]]"
+    );
+    eprintln!(
+        "Example: {} mandel.png 1000x750 -1.20,0.35 -1,0.20 --fractal burning_ship --color smooth",
         args[0]
     );
+    eprintln!(
+        "Example: {} buddha.png 1000x750 -1.20,0.35 -1,0.20 --buddhabrot --samples 20000000",
+        args[0]
+    );
+    eprintln!("KIND is one of: mandelbrot, mandelbrotN (e.g. mandelbrot3), burning_ship");
+    eprintln!("MODE is one of: grayscale, smooth");
     std::process::exit(1);
 }
 
-fn write_image(filename: &str, pixels: &[u8], (width, height): Point) -> Result<(), io::Error> {
+/// Unifies the failure modes of writing an image: the file couldn't be
+/// created, the chosen encoder rejected the pixel buffer, or the output
+/// filename's extension doesn't map to a supported format.
+#[derive(Debug)]
+enum ImageWriteError {
+    Io(io::Error),
+    Encoding(image::ImageError),
+    Tiff(tiff::TiffError),
+    UnsupportedFormat(String),
+}
+
+impl fmt::Display for ImageWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImageWriteError::Io(err) => write!(f, "error writing image file: {}", err),
+            ImageWriteError::Encoding(err) => write!(f, "error encoding image: {}", err),
+            ImageWriteError::Tiff(err) => write!(f, "error encoding tiff: {}", err),
+            ImageWriteError::UnsupportedFormat(ext) => {
+                write!(f, "unsupported output format {:?}", ext)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageWriteError {}
+
+impl From<io::Error> for ImageWriteError {
+    fn from(err: io::Error) -> Self {
+        ImageWriteError::Io(err)
+    }
+}
+
+impl From<image::ImageError> for ImageWriteError {
+    fn from(err: image::ImageError) -> Self {
+        ImageWriteError::Encoding(err)
+    }
+}
+
+impl From<tiff::TiffError> for ImageWriteError {
+    fn from(err: tiff::TiffError) -> Self {
+        ImageWriteError::Tiff(err)
+    }
+}
+
+/// Writes `pixels` to `filename`, picking PNG, TIFF, or PNM encoding from
+/// the filename's extension. PNG is the fallback when there is none.
+fn write_image(
+    filename: &str,
+    pixels: &[u8],
+    (width, height): Point,
+    color_mode: ColorMode,
+) -> Result<(), ImageWriteError> {
+    let color_type = match color_mode {
+        ColorMode::Grayscale => ColorType::Gray(8),
+        ColorMode::Smooth => ColorType::RGB(8),
+    };
+
     let output = File::create(filename)?;
-    let encoder = PNGEncoder::new(output);
 
-    encoder.encode(&pixels, width as u32, height as u32, ColorType::Gray(8))
+    match extension(filename).as_str() {
+        "tif" | "tiff" => {
+            let mut encoder = TiffEncoder::new(output)?;
+
+            match color_mode {
+                ColorMode::Grayscale => encoder
+                    .write_image_with_compression::<colortype::Gray8, Deflate>(
+                        width as u32,
+                        height as u32,
+                        Deflate::default(),
+                        pixels,
+                    )?,
+                ColorMode::Smooth => encoder
+                    .write_image_with_compression::<colortype::RGB8, Deflate>(
+                        width as u32,
+                        height as u32,
+                        Deflate::default(),
+                        pixels,
+                    )?,
+            };
+        }
+        "pnm" | "pbm" | "pgm" | "ppm" => {
+            PNMEncoder::new(output).encode(pixels, width as u32, height as u32, color_type)?
+        }
+        "png" | "" => {
+            PNGEncoder::new(output).encode(pixels, width as u32, height as u32, color_type)?
+        }
+        other => return Err(ImageWriteError::UnsupportedFormat(other.to_string())),
+    }
+
+    Ok(())
+}
+
+/// Lower-cased filename extension, or `""` if there isn't one.
+fn extension(filename: &str) -> String {
+    Path::new(filename)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase()
 }
 
-fn render(pixels: &mut [u8], bounds: Point, corner: Corner) {
+fn render(
+    pixels: &mut [u8],
+    bounds: Point,
+    corner: Corner,
+    fractal_kind: FractalKind,
+    color_mode: ColorMode,
+) {
     let (width, height) = bounds;
-    assert!(pixels.len() == width * height);
+    assert!(pixels.len() == width * height * color_mode.bytes_per_pixel());
+
+    let bands: Vec<(usize, &mut [u8])> = pixels
+        .chunks_mut(width * ROWS_PER_BAND * color_mode.bytes_per_pixel())
+        .enumerate()
+        .collect();
+
+    bands.into_par_iter().for_each(|(band_index, band)| {
+        let top = band_index * ROWS_PER_BAND;
+        let band_height = band.len() / (width * color_mode.bytes_per_pixel());
+        let band_bounds = (width, band_height);
+
+        let band_corner = Corner {
+            upper_left: pixel_to_point(&bounds, &corner, (0, top)),
+            lower_right: pixel_to_point(&bounds, &corner, (width, top + band_height)),
+        };
+
+        render_band(band, band_bounds, band_corner, fractal_kind, color_mode);
+    })
+}
+
+fn render_band(
+    pixels: &mut [u8],
+    bounds: Point,
+    corner: Corner,
+    fractal_kind: FractalKind,
+    color_mode: ColorMode,
+) {
+    let (width, height) = bounds;
+    let bytes_per_pixel = color_mode.bytes_per_pixel();
+    assert!(pixels.len() == width * height * bytes_per_pixel);
 
     let limit = 255_u32;
     let pixel_iter = (0..height).flat_map(|row| (0..width).map(move |column| (column, row)));
 
     pixel_iter.for_each(|point| {
         let (column, row) = point;
-        let point = pixel_to_point(&bounds, &corner, point);
+        let complex_point = pixel_to_point(&bounds, &corner, point);
+        let offset = (row * width + column) * bytes_per_pixel;
+        let escape = escape_time(complex_point, limit, fractal_kind);
 
-        pixels[row * width + column] =
-            escape_time(point, limit).map_or(0, |count| (limit - count) as u8);
+        match color_mode {
+            ColorMode::Grayscale => {
+                pixels[offset] = escape.map_or(0, |(count, _)| (limit - count) as u8);
+            }
+            ColorMode::Smooth => {
+                let rgb = escape
+                    .map(|(count, z)| smooth_color(count, z, limit))
+                    .unwrap_or([0, 0, 0]);
+
+                pixels[offset..offset + 3].copy_from_slice(&rgb);
+            }
+        }
     })
 }
 
@@ -82,6 +389,210 @@ fn pixel_to_point(bounds: &Point, corner: &Corner, pixel: Point) -> Complex<f64>
     Complex { re, im }
 }
 
+/// The inverse of `pixel_to_point`: maps a point in the complex plane back to
+/// the pixel whose `pixel_to_point` value lies nearest to it, or `None` if the
+/// point lies before the upper-left corner or that pixel falls outside `bounds`.
+fn point_to_pixel(bounds: &Point, corner: &Corner, point: Complex<f64>) -> Option<Point> {
+    let Corner {
+        upper_left,
+        lower_right,
+    } = corner;
+
+    let width = lower_right.re - upper_left.re;
+    let height = upper_left.im - lower_right.im;
+
+    let column = (point.re - upper_left.re) / width * bounds.0 as f64;
+    let row = (upper_left.im - point.im) / height * bounds.1 as f64;
+
+    if column < 0.0 || row < 0.0 {
+        return None;
+    }
+
+    // `column`/`row` are theoretically integral for points produced by
+    // `pixel_to_point`, but floating-point error can land them a hair below
+    // the intended value (e.g. `3.9999999999999982`); truncating with a bare
+    // `as usize` would floor that to the wrong pixel, so round instead. The
+    // bounds check must happen *after* rounding too, since an unrounded value
+    // like `199.6` passes a `< 200.0` check but rounds up to the
+    // out-of-range `200`.
+    let column = column.round() as usize;
+    let row = row.round() as usize;
+
+    if column >= bounds.0 || row >= bounds.1 {
+        return None;
+    }
+
+    Some((column, row))
+}
+
+#[cfg(test)]
+mod point_to_pixel_tests {
+    use super::{pixel_to_point, point_to_pixel, Corner, Point};
+    use num::Complex;
+
+    fn test_corner() -> Corner {
+        Corner {
+            upper_left: Complex { re: -1.0, im: 1.0 },
+            lower_right: Complex { re: 1.0, im: -1.0 },
+        }
+    }
+
+    #[test]
+    fn inverts_pixel_to_point_for_every_pixel() {
+        let bounds: Point = (20, 15);
+        let corner = test_corner();
+
+        for row in 0..bounds.1 {
+            for column in 0..bounds.0 {
+                let pixel = (column, row);
+                let point = pixel_to_point(&bounds, &corner, pixel);
+                assert_eq!(point_to_pixel(&bounds, &corner, point), Some(pixel));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_a_point_that_rounds_up_to_an_out_of_bounds_pixel() {
+        // re = 0.996 puts column at 199.6 for a 200-wide view: it passes an
+        // unrounded `< 200.0` bounds check but rounds up to the
+        // out-of-range pixel 200, which must still be rejected.
+        let bounds: Point = (200, 200);
+        let corner = test_corner();
+
+        assert_eq!(
+            point_to_pixel(
+                &bounds,
+                &corner,
+                Complex {
+                    re: 0.996,
+                    im: 0.0
+                }
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_points_outside_the_corner() {
+        let bounds: Point = (100, 100);
+        let corner = test_corner();
+
+        assert_eq!(
+            point_to_pixel(&bounds, &corner, Complex { re: -1.5, im: 0.0 }),
+            None
+        );
+        assert_eq!(
+            point_to_pixel(&bounds, &corner, Complex { re: 1.5, im: 0.0 }),
+            None
+        );
+        assert_eq!(
+            point_to_pixel(&bounds, &corner, Complex { re: 0.0, im: 1.5 }),
+            None
+        );
+        assert_eq!(
+            point_to_pixel(&bounds, &corner, Complex { re: 0.0, im: -1.5 }),
+            None
+        );
+    }
+
+    #[test]
+    fn accepts_upper_left_corner_but_rejects_lower_right() {
+        let bounds: Point = (100, 100);
+        let corner = test_corner();
+
+        assert_eq!(
+            point_to_pixel(&bounds, &corner, corner.upper_left),
+            Some((0, 0))
+        );
+        assert_eq!(point_to_pixel(&bounds, &corner, corner.lower_right), None);
+    }
+}
+
+/// Renders a Buddhabrot histogram: samples random points `c`, discards
+/// orbits that never escape, and for escaping orbits increments every
+/// in-view pixel the orbit's intermediate `z` values pass through.
+///
+/// Sampling is split across rayon tasks, each accumulating into its own
+/// histogram before the per-task histograms are summed together.
+fn render_buddhabrot(
+    histogram: &mut [u32],
+    bounds: Point,
+    corner: &Corner,
+    samples: u32,
+    limit: u32,
+) {
+    assert!(histogram.len() == bounds.0 * bounds.1);
+
+    let re_range = corner.upper_left.re..corner.lower_right.re;
+    let im_range = corner.lower_right.im..corner.upper_left.im;
+
+    let accumulated = (0..samples)
+        .into_par_iter()
+        .fold(
+            || vec![0_u32; histogram.len()],
+            |mut local_histogram, _| {
+                let mut rng = rand::thread_rng();
+                let c = Complex {
+                    re: rng.gen_range(re_range.clone()),
+                    im: rng.gen_range(im_range.clone()),
+                };
+
+                accumulate_orbit(&mut local_histogram, &bounds, corner, c, limit);
+                local_histogram
+            },
+        )
+        .reduce(
+            || vec![0_u32; histogram.len()],
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(x, y)| *x += y);
+                a
+            },
+        );
+
+    histogram.copy_from_slice(&accumulated);
+}
+
+/// Replays the orbit of `c` under `z = z*z + c`, and if it escapes before
+/// `limit` iterations, increments the histogram cell under every
+/// intermediate `z` that falls inside `bounds`.
+fn accumulate_orbit(
+    histogram: &mut [u32],
+    bounds: &Point,
+    corner: &Corner,
+    c: Complex<f64>,
+    limit: u32,
+) {
+    let mut z = Complex { re: 0.0, im: 0.0 };
+    let mut orbit = Vec::with_capacity(limit as usize);
+
+    let escaped = (0..limit).any(|_| {
+        z = z * z + c;
+        orbit.push(z);
+        z.norm_sqr() > 4.0
+    });
+
+    if !escaped {
+        return;
+    }
+
+    for z in orbit {
+        if let Some((column, row)) = point_to_pixel(bounds, corner, z) {
+            histogram[row * bounds.0 + column] += 1;
+        }
+    }
+}
+
+/// Scales a raw orbit-count histogram into an 8-bit grayscale buffer,
+/// stretching the brightest cell to full white.
+fn normalize_histogram(histogram: &[u32]) -> Vec<u8> {
+    let max = histogram.iter().copied().max().unwrap_or(0).max(1) as f64;
+
+    histogram
+        .iter()
+        .map(|&count| ((count as f64 / max) * 255.0).round() as u8)
+        .collect()
+}
+
 fn parse_complex(s: &str) -> Option<Complex<f64>> {
     parse_pair(s, ',').map(|(re, im)| Complex { re, im })
 }
@@ -119,14 +630,92 @@ fn parse_exit<'a, T, E>(sub: &'a str, s: &'a str, separator: char) -> impl FnOnc
     }
 }
 
-fn escape_time(c: Complex<f64>, limit: u32) -> Option<u32> {
-    (0..limit).skip_while(is_in_range(c)).take(1).last()
+fn escape_time(
+    c: Complex<f64>,
+    limit: u32,
+    fractal_kind: FractalKind,
+) -> Option<(u32, Complex<f64>)> {
+    let mut z = Complex { re: 0.0, im: 0.0 };
+
+    (0..limit)
+        .skip_while(|_| {
+            z = step(z, c, fractal_kind);
+            z.norm_sqr() < 4.0
+        })
+        .take(1)
+        .last()
+        .map(|count| (count, z))
+}
+
+/// Maps the escape count and final `z` to an RGB triple using a continuous
+/// (smooth) iteration count, avoiding the banding grayscale mode shows.
+fn smooth_color(count: u32, z: Complex<f64>, limit: u32) -> [u8; 3] {
+    let log_zn = z.norm_sqr().ln() / 2.0;
+    let nu = log_zn.ln() / std::f64::consts::LN_2;
+    let mu = count as f64 + 1.0 - nu;
+
+    hsv_to_rgb(360.0 * mu / limit as f64, 0.7, 1.0)
 }
 
-fn is_in_range<T>(c: Complex<f64>) -> impl FnMut(&T) -> bool {
-    let mut z = Complex { re: 0.0, im: 0.0 };
-    move |_| {
-        z = z * z + c;
-        z.norm_sqr() < 4.0
+fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> [u8; 3] {
+    let hue = hue.rem_euclid(360.0);
+    let chroma = value * saturation;
+    let hue_sector = hue / 60.0;
+    let x = chroma * (1.0 - (hue_sector.rem_euclid(2.0) - 1.0).abs());
+
+    let (r, g, b) = match hue_sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let m = value - chroma;
+
+    [
+        ((r + m) * 255.0).round() as u8,
+        ((g + m) * 255.0).round() as u8,
+        ((b + m) * 255.0).round() as u8,
+    ]
+}
+
+fn step(z: Complex<f64>, c: Complex<f64>, fractal_kind: FractalKind) -> Complex<f64> {
+    match fractal_kind {
+        FractalKind::Mandelbrot => z * z + c,
+        FractalKind::MandelbrotP(p) => z.powu(p) + c,
+        FractalKind::BurningShip => {
+            let folded = Complex {
+                re: z.re.abs(),
+                im: z.im.abs(),
+            };
+
+            folded * folded + c
+        }
+    }
+}
+
+#[cfg(test)]
+mod hsv_to_rgb_tests {
+    use super::hsv_to_rgb;
+
+    #[test]
+    fn primary_hues_at_full_saturation_and_value() {
+        assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(120.0, 1.0, 1.0), [0, 255, 0]);
+        assert_eq!(hsv_to_rgb(240.0, 1.0, 1.0), [0, 0, 255]);
+    }
+
+    #[test]
+    fn hue_wraps_at_360_degrees() {
+        assert_eq!(hsv_to_rgb(360.0, 1.0, 1.0), hsv_to_rgb(0.0, 1.0, 1.0));
+        assert_eq!(hsv_to_rgb(-10.0, 1.0, 1.0), hsv_to_rgb(350.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn zero_saturation_is_grayscale_regardless_of_hue() {
+        assert_eq!(hsv_to_rgb(180.0, 0.0, 1.0), [255, 255, 255]);
+        assert_eq!(hsv_to_rgb(37.0, 0.0, 1.0), [255, 255, 255]);
     }
 }